@@ -8,12 +8,14 @@ use crate::{
         },
         primitives::BlockId,
     },
+    fuel_asm::PanicReason,
     fuel_tx::{
         TxId,
         UtxoId,
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         Bytes32,
         ContractId,
         Nonce,
@@ -26,6 +28,7 @@ use crate::{
     },
     services::Uncommitted,
 };
+use std::collections::BTreeMap;
 use std::error::Error as StdError;
 
 /// The alias for executor result.
@@ -44,6 +47,15 @@ pub struct ExecutionResult {
     pub skipped_transactions: Vec<(TxId, Error)>,
     /// The status of the transactions execution included into the block.
     pub tx_status: Vec<TransactionExecutionStatus>,
+    /// The total gas used by all transactions in the block, accumulated in transaction
+    /// order. Mirrors the "cumulative gas used" of the last transaction in the block.
+    /// Computed by [`ExecutionResult::new`] from each entry's `gas_used`.
+    pub total_gas_used: u64,
+    /// The flattened call traces of the executed transactions, if trace capture was
+    /// requested for this execution. `None` when tracing wasn't enabled. Built from a
+    /// transaction's [`CallTrace`] tree via [`CallTrace::flatten`] when
+    /// [`ExecutionKind::captures_traces`] is `true`.
+    pub traces: Option<Vec<FlatTrace>>,
 }
 
 /// The status of a transaction after it is executed.
@@ -62,16 +74,314 @@ pub enum TransactionExecutionResult {
     Success {
         /// The result of successful transaction execution.
         result: Option<ProgramState>,
+        /// The gas actually consumed by the VM while executing this transaction.
+        gas_used: u64,
+        /// The fee actually charged for this transaction.
+        total_fee: u64,
+        /// The state changes caused by this transaction, if capture was requested.
+        /// Only ever populated for [`ExecutionKind::DryRun`].
+        state_diff: Option<StateDiff>,
     },
     /// The execution of the transaction failed.
     Failed {
         /// The result of failed transaction execution.
         result: Option<ProgramState>,
         /// The reason of execution failure.
-        reason: String,
+        reason: FailureStatus,
+        /// The gas actually consumed by the VM while executing this transaction.
+        gas_used: u64,
+        /// The fee actually charged for this transaction.
+        total_fee: u64,
+        /// The state changes caused by this transaction, if capture was requested.
+        /// Only ever populated for [`ExecutionKind::DryRun`].
+        state_diff: Option<StateDiff>,
     },
 }
 
+impl TransactionExecutionResult {
+    /// Build a `Success` result. `state_diff` is only attached when `kind` is
+    /// [`ExecutionKind::DryRun`] (see [`ExecutionKind::captures_state_diff`]); it's
+    /// dropped otherwise, so `Production`/`Validation` results never carry one. The
+    /// diff is built by the caller before this is called; this function does not
+    /// skip collecting it.
+    pub fn success(
+        result: Option<ProgramState>,
+        gas_used: u64,
+        total_fee: u64,
+        state_diff: StateDiff,
+        kind: ExecutionKind,
+    ) -> Self {
+        TransactionExecutionResult::Success {
+            result,
+            gas_used,
+            total_fee,
+            state_diff: kind.captures_state_diff().then_some(state_diff),
+        }
+    }
+
+    /// Build a `Failed` result. `state_diff` is only attached when `kind` is
+    /// [`ExecutionKind::DryRun`]; see [`TransactionExecutionResult::success`].
+    pub fn failed(
+        result: Option<ProgramState>,
+        reason: FailureStatus,
+        gas_used: u64,
+        total_fee: u64,
+        state_diff: StateDiff,
+        kind: ExecutionKind,
+    ) -> Self {
+        TransactionExecutionResult::Failed {
+            result,
+            reason,
+            gas_used,
+            total_fee,
+            state_diff: kind.captures_state_diff().then_some(state_diff),
+        }
+    }
+
+    /// The gas actually consumed by the VM while executing this transaction,
+    /// regardless of whether it was ultimately kept as `Success` or `Failed`.
+    pub fn gas_used(&self) -> u64 {
+        match self {
+            TransactionExecutionResult::Success { gas_used, .. }
+            | TransactionExecutionResult::Failed { gas_used, .. } => *gas_used,
+        }
+    }
+
+    /// The fee actually charged for this transaction, regardless of whether it was
+    /// ultimately kept as `Success` or `Failed`.
+    pub fn total_fee(&self) -> u64 {
+        match self {
+            TransactionExecutionResult::Success { total_fee, .. }
+            | TransactionExecutionResult::Failed { total_fee, .. } => *total_fee,
+        }
+    }
+}
+
+/// Sum the `gas_used` of every transaction result, in block order, mirroring
+/// "cumulative gas used".
+fn sum_gas_used(tx_status: &[TransactionExecutionStatus]) -> u64 {
+    tx_status
+        .iter()
+        .map(|status| status.result.gas_used())
+        .sum()
+}
+
+impl ExecutionResult {
+    /// Build the result of a block's execution. `total_gas_used` is computed here by
+    /// summing each transaction's `gas_used` in block order, the same way a client
+    /// would otherwise have to reconstruct it by walking `tx_status` itself.
+    pub fn new(
+        block: Block,
+        skipped_transactions: Vec<(TxId, Error)>,
+        tx_status: Vec<TransactionExecutionStatus>,
+        traces: Option<Vec<FlatTrace>>,
+    ) -> Self {
+        let total_gas_used = sum_gas_used(&tx_status);
+        Self {
+            block,
+            skipped_transactions,
+            tx_status,
+            total_gas_used,
+            traces,
+        }
+    }
+}
+
+/// The before/after of a single value touched during execution.
+#[derive(Debug, Clone)]
+pub struct Delta<T> {
+    /// The value before the transaction was executed.
+    pub from: T,
+    /// The value after the transaction was executed.
+    pub to: T,
+}
+
+/// The full set of state changes caused by a single transaction, captured for
+/// [`ExecutionKind::DryRun`] (see [`ExecutionKind::captures_state_diff`]) so
+/// `dry_run` API consumers can preview exactly what a transaction would change
+/// without committing it. Attach to a transaction's result with
+/// [`TransactionExecutionResult::success`]/[`TransactionExecutionResult::failed`],
+/// which apply that gating.
+#[derive(Debug, Clone, Default)]
+pub struct StateDiff {
+    /// The contract balances touched by the transaction, keyed by contract and asset.
+    pub balances: BTreeMap<(ContractId, AssetId), Delta<u64>>,
+    /// The contract storage slots touched by the transaction.
+    pub storage: BTreeMap<(ContractId, Bytes32), Delta<Bytes32>>,
+    /// The UTXOs created by the transaction.
+    pub created_utxos: Vec<UtxoId>,
+    /// The UTXOs spent by the transaction.
+    pub spent_utxos: Vec<UtxoId>,
+}
+
+impl StateDiff {
+    /// True if no balance, storage, or UTXO changes were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+            && self.storage.is_empty()
+            && self.created_utxos.is_empty()
+            && self.spent_utxos.is_empty()
+    }
+
+    /// Record a contract balance change for `asset_id`, overwriting any existing
+    /// delta for the same `(contract_id, asset_id)` pair.
+    pub fn record_balance_change(
+        &mut self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+        from: u64,
+        to: u64,
+    ) {
+        self.balances
+            .insert((contract_id, asset_id), Delta { from, to });
+    }
+
+    /// Record a contract storage slot change, overwriting any existing delta for the
+    /// same `(contract_id, slot)` pair.
+    pub fn record_storage_change(
+        &mut self,
+        contract_id: ContractId,
+        slot: Bytes32,
+        from: Bytes32,
+        to: Bytes32,
+    ) {
+        self.storage
+            .insert((contract_id, slot), Delta { from, to });
+    }
+}
+
+/// A stable, machine-readable status code for a kept-but-failed transaction, so that
+/// RPC clients can branch on a code instead of parsing the `Display` output of
+/// [`FailureStatus`].
+///
+/// Numbered in its own closed range starting at `0`, entirely separate from
+/// [`ExecutorErrorCode`]'s `1xxx`/`2xxx`/`3xxx` scheme for discarded transactions and
+/// block-level errors — the two enums describe different things and are never
+/// compared against each other. As with `ExecutorErrorCode`, a number is never reused
+/// once assigned, even if the variant it named is later removed; new failure classes
+/// (e.g. a new VM panic category) get the next unused number in this range.
+#[allow(missing_docs)]
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExecutionStatusCode {
+    CoinAlreadySpent = 0,
+    MessageAlreadySpent = 1,
+    OutOfGas = 2,
+    Revert = 3,
+    Panic = 4,
+    PredicateFailed = 5,
+    OutputAlreadyExists = 6,
+}
+
+impl core::fmt::Display for ExecutionStatusCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            ExecutionStatusCode::CoinAlreadySpent => "CoinAlreadySpent",
+            ExecutionStatusCode::MessageAlreadySpent => "MessageAlreadySpent",
+            ExecutionStatusCode::OutOfGas => "OutOfGas",
+            ExecutionStatusCode::Revert => "Revert",
+            ExecutionStatusCode::Panic => "Panic",
+            ExecutionStatusCode::PredicateFailed => "PredicateFailed",
+            ExecutionStatusCode::OutputAlreadyExists => "OutputAlreadyExists",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl TryFrom<u16> for ExecutionStatusCode {
+    type Error = u16;
+
+    fn try_from(code: u16) -> core::result::Result<Self, Self::Error> {
+        Ok(match code {
+            0 => ExecutionStatusCode::CoinAlreadySpent,
+            1 => ExecutionStatusCode::MessageAlreadySpent,
+            2 => ExecutionStatusCode::OutOfGas,
+            3 => ExecutionStatusCode::Revert,
+            4 => ExecutionStatusCode::Panic,
+            5 => ExecutionStatusCode::PredicateFailed,
+            6 => ExecutionStatusCode::OutputAlreadyExists,
+            other => return Err(other),
+        })
+    }
+}
+
+impl serde::Serialize for ExecutionStatusCode {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u16(*self as u16)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ExecutionStatusCode {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let code = u16::deserialize(deserializer)?;
+        ExecutionStatusCode::try_from(code).map_err(|code| {
+            serde::de::Error::custom(format!("unknown execution status code: {code}"))
+        })
+    }
+}
+
+/// The reason a kept transaction failed during execution. The `code` is the stable
+/// source of truth for clients; `detail` is a human-readable explanation that may
+/// change between releases.
+///
+/// This is the "kept but failed" half of transaction execution reporting: a
+/// transaction can already be discarded outright, surfacing as an
+/// `(TxId, Error)` pair in [`ExecutionResult::skipped_transactions`], or kept
+/// in the block with a [`TransactionExecutionResult::Failed`] outcome carrying
+/// one of these. That Kept/Discarded split isn't new here; what this type adds
+/// is a stable `code` for the kept-but-failed case, built from a real VM
+/// [`PanicReason`] via [`FailureStatus::from_panic_reason`] instead of only a
+/// free-form `detail` string.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FailureStatus {
+    /// The stable status code identifying the class of failure.
+    pub code: ExecutionStatusCode,
+    /// The VM panic reason, if the failure originated from a VM panic.
+    pub panic_reason: Option<PanicReason>,
+    /// A human-readable description of the failure.
+    pub detail: String,
+}
+
+impl FailureStatus {
+    /// Map a VM panic into a [`FailureStatus`], picking the [`ExecutionStatusCode`]
+    /// that best classifies `reason` and keeping the original panic around for
+    /// callers that need more detail than the code carries.
+    pub fn from_panic_reason(reason: PanicReason) -> Self {
+        let code = match reason {
+            PanicReason::OutOfGas => ExecutionStatusCode::OutOfGas,
+            _ => ExecutionStatusCode::Panic,
+        };
+        FailureStatus {
+            code,
+            panic_reason: Some(reason),
+            detail: reason.to_string(),
+        }
+    }
+
+    /// Build a [`FailureStatus`] that didn't originate from a VM panic, e.g. a
+    /// predicate or output-collision failure detected outside the VM.
+    pub fn new(code: ExecutionStatusCode, detail: impl Into<String>) -> Self {
+        FailureStatus {
+            code,
+            panic_reason: None,
+            detail: detail.into(),
+        }
+    }
+}
+
+impl core::fmt::Display for FailureStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.code, self.detail)
+    }
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
@@ -240,6 +550,130 @@ impl ExecutionKind {
             ExecutionKind::Validation => ExecutionTypes::Validation(t),
         }
     }
+
+    /// Whether call traces should be captured for this kind of execution. Trace
+    /// capture is most valuable for [`ExecutionKind::DryRun`] and has a real cost, so
+    /// it's skipped for `Production`/`Validation`.
+    pub fn captures_traces(&self) -> bool {
+        matches!(self, ExecutionKind::DryRun)
+    }
+
+    /// Whether per-transaction [`StateDiff`]s should be captured for this kind of
+    /// execution. Only [`ExecutionKind::DryRun`] pays for it, so `dry_run` API
+    /// consumers can preview state changes without the cost being paid in
+    /// `Production`/`Validation`.
+    pub fn captures_state_diff(&self) -> bool {
+        matches!(self, ExecutionKind::DryRun)
+    }
+}
+
+/// The kind of call captured by a [`CallTrace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallType {
+    /// No call was made; this is the root of the trace.
+    None,
+    /// A contract-to-contract call, optionally transferring an asset.
+    Call,
+    /// A call returned control to its caller.
+    Return,
+    /// A call returned data to its caller.
+    ReturnData,
+    /// A call was reverted.
+    Revert,
+    /// A call panicked.
+    Panic,
+}
+
+/// A node in the tree of calls made while executing a transaction, mirroring the
+/// internal call graph of the VM. Only populated when trace capture is requested
+/// (see [`ExecutionKind::captures_traces`]), which is most valuable for
+/// [`ExecutionKind::DryRun`]. Flatten with [`CallTrace::flatten`] before attaching to
+/// [`ExecutionResult::traces`].
+#[derive(Debug, Clone)]
+pub struct CallTrace {
+    /// The contract making the call. `None` for the root of a direct script
+    /// execution, which has no calling contract.
+    pub from: Option<ContractId>,
+    /// The contract being called. `None` for the root of a direct script execution.
+    pub to: Option<ContractId>,
+    /// The asset transferred as part of the call, if any.
+    pub asset_id: Option<AssetId>,
+    /// The amount of `asset_id` transferred as part of the call, if any.
+    pub amount: Option<u64>,
+    /// The gas made available to the call.
+    pub gas: u64,
+    /// The kind of call this trace node represents.
+    pub call_type: CallType,
+    /// The calls made from within this call, in execution order.
+    pub subtraces: Vec<CallTrace>,
+    /// The outcome of the call, if it has completed.
+    pub result: Option<ProgramState>,
+}
+
+/// A [`CallTrace`] flattened into a list indexed by its position in the call tree,
+/// making it easy to look up or filter traces without walking the tree.
+#[derive(Debug, Clone)]
+pub struct FlatTrace {
+    /// The path from the root of the call tree to this trace, e.g. `[0, 2]` is the
+    /// third subtrace of the first subtrace of the root call.
+    pub trace_address: Vec<usize>,
+    /// The contract making the call. `None` for the root of a direct script
+    /// execution, which has no calling contract.
+    pub from: Option<ContractId>,
+    /// The contract being called. `None` for the root of a direct script execution.
+    pub to: Option<ContractId>,
+    /// The asset transferred as part of the call, if any.
+    pub asset_id: Option<AssetId>,
+    /// The amount of `asset_id` transferred as part of the call, if any.
+    pub amount: Option<u64>,
+    /// The gas made available to the call.
+    pub gas: u64,
+    /// The kind of call this trace node represents.
+    pub call_type: CallType,
+    /// The outcome of the call, if it has completed.
+    pub result: Option<ProgramState>,
+}
+
+impl CallTrace {
+    /// Flatten this call tree into a list of [`FlatTrace`]s in depth-first order,
+    /// each carrying the path (`trace_address`) from the root to that node.
+    pub fn flatten(&self) -> Vec<FlatTrace> {
+        let mut out = Vec::new();
+        self.flatten_into(&mut Vec::new(), &mut out);
+        out
+    }
+
+    fn flatten_into(&self, address: &mut Vec<usize>, out: &mut Vec<FlatTrace>) {
+        out.push(FlatTrace {
+            trace_address: address.clone(),
+            from: self.from.clone(),
+            to: self.to.clone(),
+            asset_id: self.asset_id.clone(),
+            amount: self.amount,
+            gas: self.gas,
+            call_type: self.call_type,
+            result: self.result.clone(),
+        });
+        for (index, subtrace) in self.subtraces.iter().enumerate() {
+            address.push(index);
+            subtrace.flatten_into(address, out);
+            address.pop();
+        }
+    }
+}
+
+impl ExecutionResult {
+    /// Flatten and gate a block's raw per-transaction call traces for attaching to
+    /// [`ExecutionResult::traces`]. Mirrors how `state_diff` is gated in
+    /// [`TransactionExecutionResult::success`]/[`TransactionExecutionResult::failed`]:
+    /// traces are only ever flattened and attached for [`ExecutionKind::DryRun`]
+    /// (see [`ExecutionKind::captures_traces`]), so `Production`/`Validation`
+    /// executions never pay for walking the call tree. The raw `traces` are
+    /// collected by the caller; this function does not skip collecting them.
+    pub fn capture_traces(traces: &[CallTrace], kind: ExecutionKind) -> Option<Vec<FlatTrace>> {
+        kind.captures_traces()
+            .then(|| traces.iter().flat_map(CallTrace::flatten).collect())
+    }
 }
 
 #[allow(missing_docs)]
@@ -274,6 +708,20 @@ pub enum Error {
     //  `fuel-core-executor`.
     #[display(fmt = "got error during work with storage {_0}")]
     StorageError(anyhow::Error),
+    /// A storage invariant the executor relies on was violated mid-block, e.g. an
+    /// index claims a UTXO exists but the backing store doesn't have it, or a Merkle
+    /// root mismatches. Unlike [`Error::StorageError`], this is never recoverable:
+    /// the database itself is inconsistent, so the caller (e.g. the block producer)
+    /// should halt rather than skip the offending transaction. Build one with
+    /// [`Error::storage_corruption`]; [`Error::contract_utxo_missing`] shows the
+    /// canonical example of choosing between this and a normal validity error.
+    #[display(fmt = "storage corruption detected ({context}): {source}")]
+    StorageCorruption {
+        /// A short description of which invariant was violated.
+        context: String,
+        /// The underlying error, if any, that revealed the corruption.
+        source: anyhow::Error,
+    },
     #[display(fmt = "got error during work with relayer {_0}")]
     RelayerError(Box<dyn StdError + Send + Sync>),
     #[display(fmt = "Transaction({transaction_id:#x}) execution error: {error:?}")]
@@ -325,6 +773,38 @@ impl From<ValidityError> for Error {
     }
 }
 
+impl Error {
+    /// Build an [`Error::StorageCorruption`] for an invariant violation discovered
+    /// while reading `context` out of storage.
+    pub fn storage_corruption(context: impl Into<String>, source: anyhow::Error) -> Self {
+        Error::StorageCorruption {
+            context: context.into(),
+            source,
+        }
+    }
+
+    /// Build the right error for a contract UTXO lookup that came back empty.
+    ///
+    /// If `utxo_index_has_entry` is `true`, the UTXO index claims this contract has a
+    /// UTXO but storage doesn't actually have it — that's an invariant violation
+    /// ([`Error::StorageCorruption`]), since the index and the store disagree about
+    /// committed state. If it's `false`, the index simply has no record of the
+    /// contract, which is a normal, recoverable [`Error::ContractUtxoMissing`] (e.g.
+    /// an unknown contract ID in a transaction input).
+    pub fn contract_utxo_missing(contract_id: ContractId, utxo_index_has_entry: bool) -> Self {
+        if utxo_index_has_entry {
+            Error::storage_corruption(
+                format!(
+                    "utxo index has an entry for contract {contract_id:#x} but its utxo is missing from storage"
+                ),
+                anyhow::anyhow!("contract utxo index and storage disagree"),
+            )
+        } else {
+            Error::ContractUtxoMissing(contract_id)
+        }
+    }
+}
+
 #[allow(missing_docs)]
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -376,3 +856,648 @@ impl From<ValidityError> for TransactionValidityError {
         Self::Validation(CheckError::Validity(e))
     }
 }
+
+/// A stable, numeric code identifying an [`Error`] or [`TransactionValidityError`]
+/// variant, so that GraphQL/RPC layers can give clients something to match on
+/// programmatically instead of string-matching the `Display` output. Codes are never
+/// reused, even as variants are added or removed: `1xxx` covers general execution
+/// errors, `2xxx` covers transaction validity errors, and `3xxx` covers `Mint`/coinbase
+/// errors.
+#[allow(missing_docs)]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutorErrorCode {
+    TransactionIdCollision = 1001,
+    TooManyTransactions = 1002,
+    OutputAlreadyExists = 1003,
+    FeeOverflow = 1004,
+    InvalidFeeAmount = 1005,
+    InvalidBlockId = 1006,
+    ContractUtxoMissing = 1007,
+    InputTypeMismatch = 1008,
+    InvalidTransactionOutcome = 1009,
+    InvalidTransaction = 1010,
+    VmExecution = 1011,
+    Backtrace = 1012,
+    StorageError = 1013,
+    StorageCorruption = 1014,
+    RelayerError = 1015,
+    CoinAlreadySpent = 2001,
+    CoinHasNotMatured = 2002,
+    CoinDoesNotExist = 2003,
+    MessageSpendTooEarly = 2004,
+    MessageDoesNotExist = 2005,
+    MessageSenderMismatch = 2006,
+    MessageAlreadySpent = 2007,
+    MessageRecipientMismatch = 2008,
+    MessageAmountMismatch = 2009,
+    MessageNonceMismatch = 2010,
+    MessageDataMismatch = 2011,
+    InvalidContractInputIndex = 2012,
+    PredicateExecutionDisabled = 2013,
+    InvalidPredicate = 2014,
+    Validation = 2015,
+    MintMissing = 3001,
+    CoinbaseAmountMismatch = 3002,
+    MintFoundSecondEntry = 3003,
+    MintHasUnexpectedIndex = 3004,
+    MintIsNotLastTransaction = 3005,
+    MintMismatch = 3006,
+    CoinbaseCannotIncreaseBalance = 3007,
+}
+
+impl ExecutorErrorCode {
+    /// The category this code belongs to, derived from its numeric range.
+    pub fn category(&self) -> &'static str {
+        match *self as u32 {
+            1000..=1999 => "execution",
+            2000..=2999 => "validity",
+            3000..=3999 => "mint",
+            _ => "unknown",
+        }
+    }
+}
+
+impl TryFrom<u32> for ExecutorErrorCode {
+    type Error = u32;
+
+    fn try_from(code: u32) -> core::result::Result<Self, Self::Error> {
+        Ok(match code {
+            1001 => ExecutorErrorCode::TransactionIdCollision,
+            1002 => ExecutorErrorCode::TooManyTransactions,
+            1003 => ExecutorErrorCode::OutputAlreadyExists,
+            1004 => ExecutorErrorCode::FeeOverflow,
+            1005 => ExecutorErrorCode::InvalidFeeAmount,
+            1006 => ExecutorErrorCode::InvalidBlockId,
+            1007 => ExecutorErrorCode::ContractUtxoMissing,
+            1008 => ExecutorErrorCode::InputTypeMismatch,
+            1009 => ExecutorErrorCode::InvalidTransactionOutcome,
+            1010 => ExecutorErrorCode::InvalidTransaction,
+            1011 => ExecutorErrorCode::VmExecution,
+            1012 => ExecutorErrorCode::Backtrace,
+            1013 => ExecutorErrorCode::StorageError,
+            1014 => ExecutorErrorCode::StorageCorruption,
+            1015 => ExecutorErrorCode::RelayerError,
+            2001 => ExecutorErrorCode::CoinAlreadySpent,
+            2002 => ExecutorErrorCode::CoinHasNotMatured,
+            2003 => ExecutorErrorCode::CoinDoesNotExist,
+            2004 => ExecutorErrorCode::MessageSpendTooEarly,
+            2005 => ExecutorErrorCode::MessageDoesNotExist,
+            2006 => ExecutorErrorCode::MessageSenderMismatch,
+            2007 => ExecutorErrorCode::MessageAlreadySpent,
+            2008 => ExecutorErrorCode::MessageRecipientMismatch,
+            2009 => ExecutorErrorCode::MessageAmountMismatch,
+            2010 => ExecutorErrorCode::MessageNonceMismatch,
+            2011 => ExecutorErrorCode::MessageDataMismatch,
+            2012 => ExecutorErrorCode::InvalidContractInputIndex,
+            2013 => ExecutorErrorCode::PredicateExecutionDisabled,
+            2014 => ExecutorErrorCode::InvalidPredicate,
+            2015 => ExecutorErrorCode::Validation,
+            3001 => ExecutorErrorCode::MintMissing,
+            3002 => ExecutorErrorCode::CoinbaseAmountMismatch,
+            3003 => ExecutorErrorCode::MintFoundSecondEntry,
+            3004 => ExecutorErrorCode::MintHasUnexpectedIndex,
+            3005 => ExecutorErrorCode::MintIsNotLastTransaction,
+            3006 => ExecutorErrorCode::MintMismatch,
+            3007 => ExecutorErrorCode::CoinbaseCannotIncreaseBalance,
+            other => return Err(other),
+        })
+    }
+}
+
+impl serde::Serialize for ExecutorErrorCode {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u32(*self as u32)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ExecutorErrorCode {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let code = u32::deserialize(deserializer)?;
+        ExecutorErrorCode::try_from(code).map_err(|code| {
+            serde::de::Error::custom(format!("unknown executor error code: {code}"))
+        })
+    }
+}
+
+/// A structured, serializable error that an RPC layer can return to clients so that
+/// SDKs (like fuels-rs) can match on `code` programmatically instead of string-matching
+/// the `message`. Build one from any [`Error`]/[`TransactionValidityError`] with
+/// [`Error::error_info`]/[`TransactionValidityError::error_info`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExecutorErrorInfo {
+    /// The stable numeric code identifying the error variant.
+    pub code: ExecutorErrorCode,
+    /// The category the code belongs to, e.g. `"validity"` or `"mint"`.
+    pub category: String,
+    /// A human-readable description of the error.
+    pub message: String,
+}
+
+impl Error {
+    /// The stable numeric code for this error variant, for use by RPC layers that
+    /// need to give clients something to match on instead of the `Display` output.
+    pub fn error_code(&self) -> ExecutorErrorCode {
+        match self {
+            Error::TransactionIdCollision(_) => ExecutorErrorCode::TransactionIdCollision,
+            Error::TooManyTransactions => ExecutorErrorCode::TooManyTransactions,
+            Error::OutputAlreadyExists => ExecutorErrorCode::OutputAlreadyExists,
+            Error::FeeOverflow => ExecutorErrorCode::FeeOverflow,
+            Error::MintMissing => ExecutorErrorCode::MintMissing,
+            Error::MintFoundSecondEntry => ExecutorErrorCode::MintFoundSecondEntry,
+            Error::MintHasUnexpectedIndex => ExecutorErrorCode::MintHasUnexpectedIndex,
+            Error::MintIsNotLastTransaction => ExecutorErrorCode::MintIsNotLastTransaction,
+            Error::MintMismatch => ExecutorErrorCode::MintMismatch,
+            Error::CoinbaseCannotIncreaseBalance(_) => {
+                ExecutorErrorCode::CoinbaseCannotIncreaseBalance
+            }
+            Error::CoinbaseAmountMismatch => ExecutorErrorCode::CoinbaseAmountMismatch,
+            Error::TransactionValidity(e) => e.error_code(),
+            Error::StorageError(_) => ExecutorErrorCode::StorageError,
+            Error::StorageCorruption { .. } => ExecutorErrorCode::StorageCorruption,
+            Error::RelayerError(_) => ExecutorErrorCode::RelayerError,
+            Error::VmExecution { .. } => ExecutorErrorCode::VmExecution,
+            Error::InvalidTransaction(_) => ExecutorErrorCode::InvalidTransaction,
+            Error::Backtrace(_) => ExecutorErrorCode::Backtrace,
+            Error::InvalidTransactionOutcome { .. } => {
+                ExecutorErrorCode::InvalidTransactionOutcome
+            }
+            Error::InvalidFeeAmount => ExecutorErrorCode::InvalidFeeAmount,
+            Error::InvalidBlockId => ExecutorErrorCode::InvalidBlockId,
+            Error::ContractUtxoMissing(_) => ExecutorErrorCode::ContractUtxoMissing,
+            Error::MessageAlreadySpent(_) => ExecutorErrorCode::MessageAlreadySpent,
+            Error::InputTypeMismatch(_) => ExecutorErrorCode::InputTypeMismatch,
+        }
+    }
+
+    /// The structured, serializable form of this error for RPC responses.
+    pub fn error_info(&self) -> ExecutorErrorInfo {
+        let code = self.error_code();
+        ExecutorErrorInfo {
+            code,
+            category: code.category().to_string(),
+            message: self.to_string(),
+        }
+    }
+}
+
+impl TransactionValidityError {
+    /// The stable numeric code for this error variant, for use by RPC layers that
+    /// need to give clients something to match on instead of the `Display` output.
+    pub fn error_code(&self) -> ExecutorErrorCode {
+        match self {
+            TransactionValidityError::CoinAlreadySpent(_) => {
+                ExecutorErrorCode::CoinAlreadySpent
+            }
+            TransactionValidityError::CoinHasNotMatured(_) => {
+                ExecutorErrorCode::CoinHasNotMatured
+            }
+            TransactionValidityError::CoinDoesNotExist(_) => {
+                ExecutorErrorCode::CoinDoesNotExist
+            }
+            TransactionValidityError::MessageAlreadySpent(_) => {
+                ExecutorErrorCode::MessageAlreadySpent
+            }
+            TransactionValidityError::MessageSpendTooEarly(_) => {
+                ExecutorErrorCode::MessageSpendTooEarly
+            }
+            TransactionValidityError::MessageDoesNotExist(_) => {
+                ExecutorErrorCode::MessageDoesNotExist
+            }
+            TransactionValidityError::MessageSenderMismatch(_) => {
+                ExecutorErrorCode::MessageSenderMismatch
+            }
+            TransactionValidityError::MessageRecipientMismatch(_) => {
+                ExecutorErrorCode::MessageRecipientMismatch
+            }
+            TransactionValidityError::MessageAmountMismatch(_) => {
+                ExecutorErrorCode::MessageAmountMismatch
+            }
+            TransactionValidityError::MessageNonceMismatch(_) => {
+                ExecutorErrorCode::MessageNonceMismatch
+            }
+            TransactionValidityError::MessageDataMismatch(_) => {
+                ExecutorErrorCode::MessageDataMismatch
+            }
+            TransactionValidityError::InvalidContractInputIndex(_) => {
+                ExecutorErrorCode::InvalidContractInputIndex
+            }
+            TransactionValidityError::PredicateExecutionDisabled(_) => {
+                ExecutorErrorCode::PredicateExecutionDisabled
+            }
+            TransactionValidityError::InvalidPredicate(_) => {
+                ExecutorErrorCode::InvalidPredicate
+            }
+            TransactionValidityError::Validation(_) => ExecutorErrorCode::Validation,
+        }
+    }
+
+    /// The structured, serializable form of this error for RPC responses.
+    pub fn error_info(&self) -> ExecutorErrorInfo {
+        let code = self.error_code();
+        ExecutorErrorInfo {
+            code,
+            category: code.category().to_string(),
+            message: self.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn success(gas_used: u64, total_fee: u64) -> TransactionExecutionResult {
+        TransactionExecutionResult::Success {
+            result: None,
+            gas_used,
+            total_fee,
+            state_diff: None,
+        }
+    }
+
+    fn status(gas_used: u64, total_fee: u64) -> TransactionExecutionStatus {
+        TransactionExecutionStatus {
+            id: Bytes32::zeroed(),
+            result: success(gas_used, total_fee),
+        }
+    }
+
+    #[test]
+    fn gas_used_and_total_fee_read_through_success_and_failed() {
+        let success = success(10, 1);
+        assert_eq!(success.gas_used(), 10);
+        assert_eq!(success.total_fee(), 1);
+
+        let failed = TransactionExecutionResult::Failed {
+            result: None,
+            reason: FailureStatus {
+                code: ExecutionStatusCode::Revert,
+                panic_reason: None,
+                detail: "reverted".to_string(),
+            },
+            gas_used: 20,
+            total_fee: 2,
+            state_diff: None,
+        };
+        assert_eq!(failed.gas_used(), 20);
+        assert_eq!(failed.total_fee(), 2);
+    }
+
+    #[test]
+    fn sum_gas_used_accumulates_in_order() {
+        let statuses = vec![status(10, 1), status(20, 2), status(30, 3)];
+        assert_eq!(sum_gas_used(&statuses), 60);
+    }
+
+    #[test]
+    fn sum_gas_used_of_empty_block_is_zero() {
+        assert_eq!(sum_gas_used(&[]), 0);
+    }
+
+    fn leaf_trace(gas: u64) -> CallTrace {
+        CallTrace {
+            from: None,
+            to: None,
+            asset_id: None,
+            amount: None,
+            gas,
+            call_type: CallType::Call,
+            subtraces: Vec::new(),
+            result: None,
+        }
+    }
+
+    #[test]
+    fn flatten_assigns_depth_first_trace_addresses() {
+        let tree = CallTrace {
+            subtraces: vec![
+                CallTrace {
+                    subtraces: vec![leaf_trace(3)],
+                    ..leaf_trace(1)
+                },
+                leaf_trace(2),
+            ],
+            ..leaf_trace(0)
+        };
+
+        let flat = tree.flatten();
+        let addresses: Vec<_> = flat.iter().map(|t| t.trace_address.clone()).collect();
+        assert_eq!(
+            addresses,
+            vec![vec![], vec![0], vec![0, 0], vec![1]],
+            "expected depth-first root, first child, its child, then second child"
+        );
+        assert_eq!(flat.iter().map(|t| t.gas).collect::<Vec<_>>(), vec![0, 1, 3, 2]);
+    }
+
+    #[test]
+    fn flatten_of_leaf_is_a_single_entry_with_empty_address() {
+        let flat = leaf_trace(42).flatten();
+        assert_eq!(flat.len(), 1);
+        assert_eq!(flat[0].trace_address, Vec::<usize>::new());
+        assert_eq!(flat[0].gas, 42);
+    }
+
+    #[test]
+    fn execution_kind_only_captures_traces_for_dry_run() {
+        assert!(ExecutionKind::DryRun.captures_traces());
+        assert!(!ExecutionKind::Production.captures_traces());
+        assert!(!ExecutionKind::Validation.captures_traces());
+    }
+
+    #[test]
+    fn capture_traces_flattens_every_transaction_only_for_dry_run() {
+        let traces = vec![leaf_trace(1), leaf_trace(2)];
+
+        let dry_run = ExecutionResult::capture_traces(&traces, ExecutionKind::DryRun);
+        assert_eq!(
+            dry_run.map(|flat| flat.iter().map(|t| t.gas).collect::<Vec<_>>()),
+            Some(vec![1, 2])
+        );
+
+        assert!(ExecutionResult::capture_traces(&traces, ExecutionKind::Production).is_none());
+        assert!(ExecutionResult::capture_traces(&traces, ExecutionKind::Validation).is_none());
+    }
+
+    fn non_empty_state_diff() -> StateDiff {
+        let mut diff = StateDiff::default();
+        diff.record_balance_change(ContractId::zeroed(), AssetId::zeroed(), 1, 2);
+        diff
+    }
+
+    #[test]
+    fn state_diff_is_empty_reflects_recorded_changes() {
+        let mut diff = StateDiff::default();
+        assert!(diff.is_empty());
+        diff.record_storage_change(
+            ContractId::zeroed(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+        );
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn success_only_attaches_state_diff_for_dry_run() {
+        let dry_run = TransactionExecutionResult::success(
+            None,
+            0,
+            0,
+            non_empty_state_diff(),
+            ExecutionKind::DryRun,
+        );
+        let production = TransactionExecutionResult::success(
+            None,
+            0,
+            0,
+            non_empty_state_diff(),
+            ExecutionKind::Production,
+        );
+
+        match dry_run {
+            TransactionExecutionResult::Success { state_diff, .. } => {
+                assert!(state_diff.is_some())
+            }
+            _ => panic!("expected Success"),
+        }
+        match production {
+            TransactionExecutionResult::Success { state_diff, .. } => {
+                assert!(state_diff.is_none())
+            }
+            _ => panic!("expected Success"),
+        }
+    }
+
+    #[test]
+    fn failed_only_attaches_state_diff_for_dry_run() {
+        let reason = FailureStatus {
+            code: ExecutionStatusCode::Revert,
+            panic_reason: None,
+            detail: "reverted".to_string(),
+        };
+        let validation = TransactionExecutionResult::failed(
+            None,
+            reason.clone(),
+            0,
+            0,
+            non_empty_state_diff(),
+            ExecutionKind::Validation,
+        );
+        match validation {
+            TransactionExecutionResult::Failed { state_diff, .. } => {
+                assert!(state_diff.is_none())
+            }
+            _ => panic!("expected Failed"),
+        }
+    }
+
+    #[test]
+    fn contract_utxo_missing_is_storage_corruption_when_index_disagrees_with_storage() {
+        let contract_id = ContractId::zeroed();
+        let error = Error::contract_utxo_missing(contract_id, true);
+        match error {
+            Error::StorageCorruption { context, .. } => {
+                assert!(context.contains(&format!("{contract_id:#x}")))
+            }
+            _ => panic!("expected StorageCorruption"),
+        }
+    }
+
+    #[test]
+    fn contract_utxo_missing_is_plain_error_when_index_has_no_entry() {
+        let contract_id = ContractId::zeroed();
+        let error = Error::contract_utxo_missing(contract_id, false);
+        assert!(matches!(error, Error::ContractUtxoMissing(id) if id == contract_id));
+    }
+
+    #[test]
+    fn failure_status_from_panic_reason_maps_out_of_gas() {
+        let status = FailureStatus::from_panic_reason(PanicReason::OutOfGas);
+        assert_eq!(status.code, ExecutionStatusCode::OutOfGas);
+        assert_eq!(status.panic_reason, Some(PanicReason::OutOfGas));
+    }
+
+    #[test]
+    fn failure_status_from_panic_reason_falls_back_to_panic_code() {
+        let status = FailureStatus::from_panic_reason(PanicReason::ContractNotFound);
+        assert_eq!(status.code, ExecutionStatusCode::Panic);
+        assert_eq!(status.panic_reason, Some(PanicReason::ContractNotFound));
+    }
+
+    #[test]
+    fn failure_status_new_has_no_panic_reason() {
+        let status = FailureStatus::new(ExecutionStatusCode::PredicateFailed, "bad predicate");
+        assert_eq!(status.code, ExecutionStatusCode::PredicateFailed);
+        assert!(status.panic_reason.is_none());
+        assert_eq!(status.detail, "bad predicate");
+    }
+
+    const ALL_EXECUTION_STATUS_CODES: [ExecutionStatusCode; 7] = [
+        ExecutionStatusCode::CoinAlreadySpent,
+        ExecutionStatusCode::MessageAlreadySpent,
+        ExecutionStatusCode::OutOfGas,
+        ExecutionStatusCode::Revert,
+        ExecutionStatusCode::Panic,
+        ExecutionStatusCode::PredicateFailed,
+        ExecutionStatusCode::OutputAlreadyExists,
+    ];
+
+    #[test]
+    fn execution_status_code_round_trips_through_try_from_u16() {
+        for code in ALL_EXECUTION_STATUS_CODES {
+            let round_tripped = ExecutionStatusCode::try_from(code as u16)
+                .unwrap_or_else(|_| panic!("{code:?} did not round-trip"));
+            assert_eq!(round_tripped, code);
+        }
+    }
+
+    #[test]
+    fn execution_status_code_try_from_rejects_unknown_values() {
+        assert_eq!(ExecutionStatusCode::try_from(7), Err(7));
+        assert_eq!(ExecutionStatusCode::try_from(u16::MAX), Err(u16::MAX));
+    }
+
+    #[test]
+    fn execution_status_code_deserializes_from_its_own_numeric_code() {
+        use serde::de::{
+            Deserialize,
+            IntoDeserializer,
+            value::{
+                Error as DeError,
+                U16Deserializer,
+            },
+        };
+
+        for code in ALL_EXECUTION_STATUS_CODES {
+            let deserializer: U16Deserializer<DeError> = (code as u16).into_deserializer();
+            let deserialized = ExecutionStatusCode::deserialize(deserializer).unwrap();
+            assert_eq!(deserialized, code);
+        }
+    }
+
+    #[test]
+    fn execution_status_code_deserialize_rejects_unknown_values() {
+        use serde::de::{
+            Deserialize,
+            IntoDeserializer,
+            value::{
+                Error as DeError,
+                U16Deserializer,
+            },
+        };
+
+        let deserializer: U16Deserializer<DeError> = 7u16.into_deserializer();
+        assert!(ExecutionStatusCode::deserialize(deserializer).is_err());
+    }
+
+    const ALL_EXECUTOR_ERROR_CODES: [ExecutorErrorCode; 37] = [
+        ExecutorErrorCode::TransactionIdCollision,
+        ExecutorErrorCode::TooManyTransactions,
+        ExecutorErrorCode::OutputAlreadyExists,
+        ExecutorErrorCode::FeeOverflow,
+        ExecutorErrorCode::InvalidFeeAmount,
+        ExecutorErrorCode::InvalidBlockId,
+        ExecutorErrorCode::ContractUtxoMissing,
+        ExecutorErrorCode::InputTypeMismatch,
+        ExecutorErrorCode::InvalidTransactionOutcome,
+        ExecutorErrorCode::InvalidTransaction,
+        ExecutorErrorCode::VmExecution,
+        ExecutorErrorCode::Backtrace,
+        ExecutorErrorCode::StorageError,
+        ExecutorErrorCode::StorageCorruption,
+        ExecutorErrorCode::RelayerError,
+        ExecutorErrorCode::CoinAlreadySpent,
+        ExecutorErrorCode::CoinHasNotMatured,
+        ExecutorErrorCode::CoinDoesNotExist,
+        ExecutorErrorCode::MessageSpendTooEarly,
+        ExecutorErrorCode::MessageDoesNotExist,
+        ExecutorErrorCode::MessageSenderMismatch,
+        ExecutorErrorCode::MessageAlreadySpent,
+        ExecutorErrorCode::MessageRecipientMismatch,
+        ExecutorErrorCode::MessageAmountMismatch,
+        ExecutorErrorCode::MessageNonceMismatch,
+        ExecutorErrorCode::MessageDataMismatch,
+        ExecutorErrorCode::InvalidContractInputIndex,
+        ExecutorErrorCode::PredicateExecutionDisabled,
+        ExecutorErrorCode::InvalidPredicate,
+        ExecutorErrorCode::Validation,
+        ExecutorErrorCode::MintMissing,
+        ExecutorErrorCode::CoinbaseAmountMismatch,
+        ExecutorErrorCode::MintFoundSecondEntry,
+        ExecutorErrorCode::MintHasUnexpectedIndex,
+        ExecutorErrorCode::MintIsNotLastTransaction,
+        ExecutorErrorCode::MintMismatch,
+        ExecutorErrorCode::CoinbaseCannotIncreaseBalance,
+    ];
+
+    #[test]
+    fn executor_error_code_round_trips_through_try_from_u32() {
+        for code in ALL_EXECUTOR_ERROR_CODES {
+            let round_tripped = ExecutorErrorCode::try_from(code as u32)
+                .unwrap_or_else(|_| panic!("{code:?} did not round-trip"));
+            assert_eq!(round_tripped, code);
+        }
+    }
+
+    #[test]
+    fn executor_error_code_try_from_rejects_unknown_values() {
+        assert_eq!(ExecutorErrorCode::try_from(0), Err(0));
+        assert_eq!(ExecutorErrorCode::try_from(1016), Err(1016));
+        assert_eq!(ExecutorErrorCode::try_from(2016), Err(2016));
+        assert_eq!(ExecutorErrorCode::try_from(3008), Err(3008));
+    }
+
+    #[test]
+    fn executor_error_code_category_matches_its_numeric_range() {
+        for code in ALL_EXECUTOR_ERROR_CODES {
+            let category = code.category();
+            match code as u32 {
+                1000..=1999 => assert_eq!(category, "execution"),
+                2000..=2999 => assert_eq!(category, "validity"),
+                3000..=3999 => assert_eq!(category, "mint"),
+                other => panic!("{other} outside the documented code ranges"),
+            }
+        }
+    }
+
+    #[test]
+    fn executor_error_code_deserializes_from_its_own_numeric_code() {
+        use serde::de::{
+            Deserialize,
+            IntoDeserializer,
+            value::{
+                Error as DeError,
+                U32Deserializer,
+            },
+        };
+
+        for code in ALL_EXECUTOR_ERROR_CODES {
+            let deserializer: U32Deserializer<DeError> = (code as u32).into_deserializer();
+            let deserialized = ExecutorErrorCode::deserialize(deserializer).unwrap();
+            assert_eq!(deserialized, code);
+        }
+    }
+
+    #[test]
+    fn executor_error_code_deserialize_rejects_unknown_values() {
+        use serde::de::{
+            Deserialize,
+            IntoDeserializer,
+            value::{
+                Error as DeError,
+                U32Deserializer,
+            },
+        };
+
+        let deserializer: U32Deserializer<DeError> = 0u32.into_deserializer();
+        assert!(ExecutorErrorCode::deserialize(deserializer).is_err());
+    }
+}